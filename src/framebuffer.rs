@@ -0,0 +1,132 @@
+use crate::{Command, Result, ILI9486};
+
+use embedded_graphics::{
+    drawable::Pixel,
+    geometry::Size,
+    pixelcolor::{
+        raw::{RawData, RawU16},
+        Rgb565,
+    },
+    DrawTarget,
+};
+
+use display_interface::DataFormat::U16BEIter;
+use display_interface::WriteOnlyDataCommand;
+
+/// Coalesced min/max bounds of the pixels written since the last flush.
+#[derive(Copy, Clone)]
+struct DirtyRect {
+    min_x: usize,
+    min_y: usize,
+    max_x: usize,
+    max_y: usize,
+}
+
+/// A software framebuffer backed by a user-provided `&mut [u16]` buffer.
+///
+/// `draw_pixel`/`draw_rectangle` writes accumulate in RAM along with a
+/// coalesced dirty rectangle, turning dozens of tiny per-primitive bus
+/// transactions into a single windowed [`FramebufferTarget::flush`].
+pub struct FramebufferTarget<'a> {
+    buffer: &'a mut [u16],
+    width: usize,
+    height: usize,
+    dirty: Option<DirtyRect>,
+}
+
+impl<'a> FramebufferTarget<'a> {
+    /// Wrap `buffer` as a `width` x `height` framebuffer. `buffer.len()` must
+    /// equal `width * height`.
+    pub fn new(buffer: &'a mut [u16], width: usize, height: usize) -> Self {
+        assert_eq!(buffer.len(), width * height);
+        FramebufferTarget {
+            buffer,
+            width,
+            height,
+            dirty: None,
+        }
+    }
+
+    fn mark_dirty(&mut self, x: usize, y: usize) {
+        self.dirty = Some(match self.dirty.take() {
+            None => DirtyRect {
+                min_x: x,
+                min_y: y,
+                max_x: x,
+                max_y: y,
+            },
+            Some(d) => DirtyRect {
+                min_x: d.min_x.min(x),
+                min_y: d.min_y.min(y),
+                max_x: d.max_x.max(x),
+                max_y: d.max_y.max(y),
+            },
+        });
+    }
+
+    /// Stream the pixels covered by the accumulated dirty rectangle to the
+    /// panel over a single window, then clear the dirty region.
+    ///
+    /// The first row is sent with `MemoryWrite`; every subsequent row of the
+    /// rectangle is sent with `MemoryWriteContinue`, since the rows are not
+    /// contiguous in the backing buffer unless the rectangle spans the full
+    /// width.
+    ///
+    /// On error the dirty region is left intact so a retried flush re-sends
+    /// the rows that may not have reached the panel.
+    pub fn flush<IFACE, RESET, TE>(&mut self, display: &mut ILI9486<IFACE, RESET, TE>) -> Result
+    where
+        IFACE: WriteOnlyDataCommand,
+    {
+        let dirty = match self.dirty {
+            Some(dirty) => dirty,
+            None => return Ok(()),
+        };
+
+        display.set_window(dirty.min_x as u16, dirty.min_y as u16, dirty.max_x as u16, dirty.max_y as u16)?;
+
+        for (i, y) in (dirty.min_y..=dirty.max_y).enumerate() {
+            let row_start = y * self.width + dirty.min_x;
+            let row_end = y * self.width + dirty.max_x + 1;
+            let cmd = if i == 0 { Command::MemoryWrite } else { Command::MemoryWriteContinue };
+            display.command(cmd, &[])?;
+            display
+                .interface
+                .send_data(U16BEIter(&mut self.buffer[row_start..row_end].iter().copied()))?;
+        }
+
+        self.dirty = None;
+        Ok(())
+    }
+}
+
+impl<'a> DrawTarget<Rgb565> for FramebufferTarget<'a> {
+    type Error = core::convert::Infallible;
+
+    fn size(&self) -> Size {
+        Size::new(self.width as u32, self.height as u32)
+    }
+
+    fn draw_pixel(&mut self, pixel: Pixel<Rgb565>) -> Result<(), Self::Error> {
+        let Pixel(pos, color) = pixel;
+
+        if pos.x < 0 || pos.y < 0 || pos.x >= self.width as i32 || pos.y >= self.height as i32 {
+            return Ok(());
+        }
+
+        let (x, y) = (pos.x as usize, pos.y as usize);
+        self.buffer[y * self.width + x] = RawU16::from(color).into_inner();
+        self.mark_dirty(x, y);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Rgb565) -> Result<(), Self::Error> {
+        let value = RawU16::from(color).into_inner();
+        for pixel in self.buffer.iter_mut() {
+            *pixel = value;
+        }
+        self.mark_dirty(0, 0);
+        self.mark_dirty(self.width - 1, self.height - 1);
+        Ok(())
+    }
+}
@@ -14,7 +14,7 @@ use embedded_graphics::{
     DrawTarget,
 };
 
-impl<IFACE, RESET> DrawTarget<Rgb565> for ILI9486<IFACE, RESET>
+impl<IFACE, RESET, TE> DrawTarget<Rgb565> for ILI9486<IFACE, RESET, TE>
 where
     IFACE: display_interface::WriteOnlyDataCommand,
 {
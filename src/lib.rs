@@ -10,9 +10,24 @@ use display_interface::WriteOnlyDataCommand;
 #[cfg(feature = "graphics")]
 mod graphics;
 
+#[cfg(feature = "graphics")]
+mod framebuffer;
+
+#[cfg(feature = "graphics")]
+pub use framebuffer::FramebufferTarget;
+
 #[cfg(feature = "graphics-core")]
 mod graphics_core;
 
+#[cfg(feature = "read")]
+mod read;
+
+#[cfg(feature = "read")]
+pub use read::ReadWriteDataCommand;
+
+#[cfg(feature = "te")]
+mod te;
+
 pub use embedded_hal::spi::MODE_0 as SPI_MODE;
 
 pub use display_interface::DisplayError;
@@ -101,6 +116,21 @@ impl From<DisplayMode> for u8 {
     }
 }
 
+/// Placeholder tearing-effect pin type used when no TE pin is connected.
+///
+/// This is the default third type parameter of [`ILI9486`]; attach a real
+/// pin with [`ILI9486::with_tearing_effect_pin`] to unlock TE-synced draws.
+pub struct NoTearingEffectPin;
+
+/// Trigger condition for the panel's tearing-effect (TE) output.
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum TearingEffectMode {
+    /// TE pulses once per frame, at V-blank only.
+    VBlankOnly = 0,
+    /// TE pulses at both V-blank and H-blank.
+    VAndHBlank = 1,
+}
+
 /// There are two method for drawing to the screen:
 /// [Ili9341::draw_raw_iter] and [Ili9341::draw_raw_slice]
 ///
@@ -116,15 +146,17 @@ impl From<DisplayMode> for u8 {
 /// - As soon as a pixel is received, an internal counter is incremented,
 ///   and the next word will fill the next pixel (the adjacent on the right, or
 ///   the first of the next row if the row ended)
-pub struct ILI9486<IFACE, RESET> {
+pub struct ILI9486<IFACE, RESET, TE = NoTearingEffectPin> {
     interface: IFACE,
     reset: RESET,
+    te: Option<TE>,
+    te_mode: Option<TearingEffectMode>,
     width: usize,
     height: usize,
     mode: DisplayMode,
 }
 
-impl<IFACE, RESET> ILI9486<IFACE, RESET>
+impl<IFACE, RESET> ILI9486<IFACE, RESET, NoTearingEffectPin>
     where
         IFACE: WriteOnlyDataCommand,
         RESET: OutputPin,
@@ -152,6 +184,8 @@ impl<IFACE, RESET> ILI9486<IFACE, RESET>
         let mut ili9486 = ILI9486 {
             interface,
             reset,
+            te: None,
+            te_mode: None,
             width: SIZE::WIDTH,
             height: SIZE::HEIGHT,
             mode: DisplayMode::default(),
@@ -190,7 +224,7 @@ impl<IFACE, RESET> ILI9486<IFACE, RESET>
 }
 
 
-impl<IFACE, RESET> ILI9486<IFACE, RESET>
+impl<IFACE, RESET, TE> ILI9486<IFACE, RESET, TE>
     where
         IFACE: WriteOnlyDataCommand,
 {
@@ -302,6 +336,95 @@ impl<IFACE, RESET> ILI9486<IFACE, RESET>
         self.draw_raw_iter(x0, y0, x1, y1, data.iter().copied())
     }
 
+    /// Put the display into sleep mode, switching off the panel driver and
+    /// gamma/gray-scale voltage generators to reduce power draw.
+    ///
+    /// The panel requires a 120us settle delay after `SleepIn` before any
+    /// other command is issued, which this method honors.
+    pub fn sleep(&mut self, delay: &mut impl DelayUs<u32>) -> Result {
+        self.command(Command::SleepIn, &[])?;
+        delay.delay_us(120);
+        Ok(())
+    }
+
+    /// Wake the display from sleep mode, restoring normal operation.
+    ///
+    /// Honors the same 120us settle delay used during [`ILI9486::new`].
+    pub fn wake(&mut self, delay: &mut impl DelayUs<u32>) -> Result {
+        self.command(Command::SleepOut, &[])?;
+        delay.delay_us(120);
+        Ok(())
+    }
+
+    /// Enable or disable idle mode, which reduces the display to 8 colors
+    /// for low-power standby.
+    pub fn set_idle_mode(&mut self, on: bool) -> Result {
+        self.command(if on { Command::IdleModeOn } else { Command::IdleModeOff }, &[])
+    }
+
+    /// Turn the display output on or off. The display RAM and settings are
+    /// retained while off, so drawing can resume without reinitializing.
+    pub fn set_display_on(&mut self, on: bool) -> Result {
+        self.command(if on { Command::DisplayOn } else { Command::DisplayOff }, &[])
+    }
+
+    /// Set the frame-rate division ratio and clocks-per-line for the given
+    /// [`FrameRateMode`].
+    ///
+    /// `division_ratio` selects the fosc division (`DIVA`, 0..=3) and `rtna`
+    /// selects the number of clocks per line (`RTNA`, 0..=0x1f).
+    pub fn set_frame_rate(&mut self, mode: FrameRateMode, division_ratio: u8, rtna: u8) -> Result {
+        let cmd = match mode {
+            FrameRateMode::Normal => Command::FrameRateControlNormal,
+            FrameRateMode::Idle => Command::FrameRateControlIdle,
+            FrameRateMode::Partial => Command::FrameRateControlPartial,
+        };
+        self.command(cmd, &[division_ratio & 0x03, rtna & 0x1f])
+    }
+
+    /// Enable or disable display (column) inversion.
+    pub fn set_inversion(&mut self, on: bool) -> Result {
+        self.command(if on { Command::DisplayInversionOn } else { Command::DisplayInversionOff }, &[])
+    }
+
+    /// Set the positive polarity gamma correction curve (`PGAMCTRL`).
+    pub fn set_positive_gamma(&mut self, curve: &[u8; 15]) -> Result {
+        self.command(Command::PGAMCTRL, curve)
+    }
+
+    /// Set the negative polarity gamma correction curve (`NGAMCTRL`).
+    pub fn set_negative_gamma(&mut self, curve: &[u8; 15]) -> Result {
+        self.command(Command::NGAMCTRL, curve)
+    }
+
+    /// Set the digital gamma correction lookup tables used to fine-tune the
+    /// positive and negative gamma curves above.
+    pub fn set_digital_gamma(&mut self, positive: &[u8; 15], negative: &[u8; 15]) -> Result {
+        self.command(Command::DigitalGammaControl1, positive)?;
+        self.command(Command::DigitalGammaControl2, negative)
+    }
+
+    /// Restrict the active display area to the rows between `start_row` and
+    /// `end_row` (inclusive), powering down and blanking the rest of the
+    /// panel to save power. Call [`ILI9486::partial_mode_on`] to engage it.
+    pub fn set_partial_area(&mut self, start_row: u16, end_row: u16) -> Result {
+        self.command(
+            Command::PartialArea,
+            &[
+                (start_row >> 8) as u8,
+                (start_row & 0xff) as u8,
+                (end_row >> 8) as u8,
+                (end_row & 0xff) as u8,
+            ],
+        )
+    }
+
+    /// Engage partial-area display mode, restricting refresh and power draw
+    /// to the band configured with [`ILI9486::set_partial_area`].
+    pub fn partial_mode_on(&mut self) -> Result {
+        self.command(Command::PartialModeOn, &[])
+    }
+
     /// Change the orientation of the screen
     pub fn set_display_mode(&mut self, mode: DisplayMode) -> Result {
         if self.mode.orientation != mode.orientation {
@@ -313,7 +436,7 @@ impl<IFACE, RESET> ILI9486<IFACE, RESET>
     }
 }
 
-impl<IFACE, RESET> ILI9486<IFACE, RESET> {
+impl<IFACE, RESET, TE> ILI9486<IFACE, RESET, TE> {
     /// Get the current screen width. It can change based on the current orientation
     pub fn width(&self) -> usize {
         self.width
@@ -325,6 +448,73 @@ impl<IFACE, RESET> ILI9486<IFACE, RESET> {
     }
 }
 
+/// Selects which of the panel's three frame-rate control registers a call
+/// to [`ILI9486::set_frame_rate`] targets.
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum FrameRateMode {
+    Normal,
+    Idle,
+    Partial,
+}
+
+const BCTRL: u8 = 1 << 5;
+const DD: u8 = 1 << 3;
+const BL: u8 = 1 << 2;
+
+/// Content-Adaptive Brightness Control mode, selecting how aggressively the
+/// panel dims the backlight based on displayed image content.
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum CABCMode {
+    Off = 0b00,
+    UserInterface = 0b01,
+    StillPicture = 0b10,
+    MovingImage = 0b11,
+}
+
+impl<IFACE, RESET, TE> ILI9486<IFACE, RESET, TE>
+    where
+        IFACE: WriteOnlyDataCommand,
+{
+    /// Set the panel backlight brightness, from 0 (off) to 255 (max).
+    ///
+    /// Has no effect unless brightness control has been enabled with
+    /// [`ILI9486::set_brightness_control`].
+    pub fn set_brightness(&mut self, level: u8) -> Result {
+        self.command(Command::WriteDisplayBrightnessValue, &[level])
+    }
+
+    /// Enable or disable the brightness-control block (BCTRL), the dimming
+    /// function (DD) and the backlight (BL) in the CTRL display register.
+    pub fn set_brightness_control(
+        &mut self,
+        brightness_control: bool,
+        dimming: bool,
+        backlight_on: bool,
+    ) -> Result {
+        let mut value = 0;
+        if brightness_control {
+            value |= BCTRL;
+        }
+        if dimming {
+            value |= DD;
+        }
+        if backlight_on {
+            value |= BL;
+        }
+        self.command(Command::WriteCTRLDisplayValue, &[value])
+    }
+
+    /// Select the content-adaptive backlight control mode.
+    pub fn set_cabc_mode(&mut self, mode: CABCMode) -> Result {
+        self.command(Command::WriteCABrigthnessControl, &[mode as u8])
+    }
+
+    /// Set the minimum brightness the CABC algorithm is allowed to dim down to.
+    pub fn set_cabc_min_brightness(&mut self, level: u8) -> Result {
+        self.command(Command::WriteCABCMinBrigthness, &[level])
+    }
+}
+
 /// Scroller must be provided in order to scroll the screen. It can only be obtained
 /// by configuring the screen for scrolling.
 pub struct Scroller {
@@ -0,0 +1,59 @@
+use crate::{Command, Result, ILI9486};
+
+use display_interface::DisplayError;
+
+/// A display interface that can, in addition to writing, clock out a
+/// command and sample back parameter bytes.
+///
+/// For SPI this means the implementation itself issues the command byte,
+/// discards the interface's read dummy bit/byte, then samples MISO for
+/// `buffer.len()` bytes. Callers must not send `cmd` themselves first.
+pub trait ReadWriteDataCommand: display_interface::WriteOnlyDataCommand {
+    /// Issue `cmd`, then read back `buffer.len()` parameter bytes into
+    /// `buffer`, with any read dummy bit/byte already discarded.
+    fn read_data(&mut self, cmd: u8, buffer: &mut [u8]) -> Result<(), DisplayError>;
+}
+
+impl<IFACE, RESET, TE> ILI9486<IFACE, RESET, TE>
+where
+    IFACE: ReadWriteDataCommand,
+{
+    fn read(&mut self, cmd: Command, buffer: &mut [u8]) -> Result {
+        self.interface.read_data(cmd as u8, buffer)
+    }
+
+    /// Read the 3-byte display identification: manufacturer, driver version
+    /// and driver ID.
+    ///
+    /// Returns `[u8; 3]` rather than the 4 bytes `ReadDisplayId` clocks out,
+    /// since `read_data` already discards the leading dummy byte.
+    pub fn read_display_id(&mut self) -> Result<[u8; 3]> {
+        let mut buffer = [0u8; 3];
+        self.read(Command::ReadDisplayId, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Read the display power mode register, reporting booster, idle,
+    /// partial, sleep, normal and display-on states.
+    pub fn read_power_mode(&mut self) -> Result<u8> {
+        let mut buffer = [0u8];
+        self.read(Command::ReadDisplayPowerMode, &mut buffer)?;
+        Ok(buffer[0])
+    }
+
+    /// Read the self-diagnostic result register, reporting the outcome of
+    /// the panel's internal register and functionality checks.
+    pub fn read_self_diagnostic(&mut self) -> Result<u8> {
+        let mut buffer = [0u8];
+        self.read(Command::ReadDisplaySelfDiagResult, &mut buffer)?;
+        Ok(buffer[0])
+    }
+
+    /// Read the checksum of the last transmitted data, useful for
+    /// detecting brown-outs or register corruption at runtime.
+    pub fn read_checksum(&mut self) -> Result<u8> {
+        let mut buffer = [0u8];
+        self.read(Command::ReadFirstChecksum, &mut buffer)?;
+        Ok(buffer[0])
+    }
+}
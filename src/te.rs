@@ -0,0 +1,81 @@
+use embedded_hal::digital::v2::InputPin;
+
+use crate::{Command, Result, TearingEffectMode, ILI9486};
+
+impl<IFACE, RESET, TE> ILI9486<IFACE, RESET, TE> {
+    /// Attach a tearing-effect (TE) input pin, unlocking TE-synchronized
+    /// draws and [`ILI9486::is_in_vblank`].
+    pub fn with_tearing_effect_pin<NEWTE: InputPin>(self, te: NEWTE) -> ILI9486<IFACE, RESET, NEWTE> {
+        ILI9486 {
+            interface: self.interface,
+            reset: self.reset,
+            te: Some(te),
+            te_mode: self.te_mode,
+            width: self.width,
+            height: self.height,
+            mode: self.mode,
+        }
+    }
+}
+
+impl<IFACE, RESET, TE> ILI9486<IFACE, RESET, TE>
+where
+    IFACE: display_interface::WriteOnlyDataCommand,
+{
+    /// Enable the tearing-effect output so full-frame writes can be
+    /// synchronized to the panel's V-blank.
+    pub fn enable_tearing_effect(&mut self, mode: TearingEffectMode) -> Result {
+        self.command(Command::TearingEffectLineOn, &[mode as u8])?;
+        self.te_mode = Some(mode);
+        Ok(())
+    }
+
+    /// Disable the tearing-effect output.
+    pub fn disable_tearing_effect(&mut self) -> Result {
+        self.command(Command::TearingEffectLineOff, &[])?;
+        self.te_mode = None;
+        Ok(())
+    }
+
+    /// Select the scanline at which the TE signal triggers.
+    pub fn set_tear_scanline(&mut self, line: u16) -> Result {
+        self.command(Command::WriteTearScanLine, &[(line >> 8) as u8, (line & 0xff) as u8])
+    }
+}
+
+impl<IFACE, RESET, TE> ILI9486<IFACE, RESET, TE>
+where
+    IFACE: display_interface::WriteOnlyDataCommand,
+    TE: InputPin,
+{
+    /// Returns `true` if the TE pin currently indicates the panel is in
+    /// V-blank, i.e. it is safe to start a new frame write without tearing.
+    ///
+    /// Always returns `false` unless a TE pin was attached with
+    /// [`ILI9486::with_tearing_effect_pin`] *and* [`ILI9486::enable_tearing_effect`]
+    /// was configured with [`TearingEffectMode::VBlankOnly`] — in
+    /// [`TearingEffectMode::VAndHBlank`] a high pin reading could be an
+    /// H-blank pulse, not the real frame boundary, so it can't be trusted here.
+    pub fn is_in_vblank(&self) -> bool {
+        self.te_mode == Some(TearingEffectMode::VBlankOnly)
+            && self.te.as_ref().map_or(false, |te| te.is_high().unwrap_or(false))
+    }
+
+    /// Like [`ILI9486::draw_raw_slice`], but blocks until the TE pin signals
+    /// V-blank before issuing the memory write, so moving content and
+    /// scrolling updates don't tear.
+    ///
+    /// Only waits when tearing effect was enabled in
+    /// [`TearingEffectMode::VBlankOnly`] (see [`ILI9486::is_in_vblank`]);
+    /// otherwise it behaves exactly like `draw_raw_slice`, since an
+    /// H-blank pulse can't be distinguished from the real V-blank.
+    ///
+    /// The wait is an unbounded busy loop with no timeout: if the TE pin is
+    /// wired incorrectly, or never actually pulses, this call hangs forever.
+    pub fn draw_raw_slice_synced(&mut self, x0: u16, y0: u16, x1: u16, y1: u16, data: &[u16]) -> Result {
+        if self.te_mode == Some(TearingEffectMode::VBlankOnly) {
+            while !self.is_in_vblank() {}
+        }
+        self.draw_raw_slice(x0, y0, x1, y1, data)
+    }
+}